@@ -0,0 +1,3 @@
+pub mod foo {
+    pub fn func() {}
+}
@@ -0,0 +1,4 @@
+pub mod foo {
+    pub fn func() {}
+    pub fn func2() {}
+}